@@ -1,6 +1,32 @@
 //! A simple macro to define nested structs which are populated from a list of expected variables passed at compile-tine.
 //!
 //! Names are converted to `snake_case` and periods are treated as separators indicating a nested struct.
+//!
+//! A variable name may carry a `:TYPE` suffix to parse it into something other than `String`, e.g.
+//! `"DATABASE.PORT:int"`. Supported types are `int`/`integer` (`i64`), `float` (`f64`),
+//! `bool`/`boolean`, `string`/`bytes`/`asis` (the default, a plain `String`), `ts`/`timestamp`
+//! (an RFC 3339 `chrono::DateTime<Utc>`), `ts=FORMAT` (a naive/local timestamp parsed with a
+//! `chrono` format string, e.g. `"BUILD.TIME:ts=%Y-%m-%dT%H:%M:%S"`), and `tstz=FORMAT` (a
+//! timezone-aware timestamp whose format string includes an offset token, e.g.
+//! `"BUILD.TIME:tstz=%Y-%m-%d %H:%M:%S%z"`).
+//!
+//! Alongside `new()`, which panics if any variable is missing or invalid, every generated
+//! struct also gets a `try_new() -> Result<Self, {Root}VarsError>` that collects every problem
+//! across the whole tree before returning. `{Root}VarsError`, `{Root}VarError`, and
+//! `{Root}VarErrorKind` are namespaced by the invocation's root struct name, so two invocations
+//! in the same scope never collide on these types either.
+//!
+//! A trailing `?` marks a variable optional, generating an `Option<T>` field that is `None` when
+//! unset, e.g. `"API.TIMEOUT?"`. A trailing `=DEFAULT` instead falls back to a literal when the
+//! variable is absent, e.g. `"API.TIMEOUT=30"`.
+//!
+//! An invocation may open with a configuration block before any variable names. Without it, the
+//! literal dotted name (e.g. `"DATABASE.HOST"`) is used unchanged as the real `std::env::var`
+//! key, same as ever. Setting `prefix = "..."` switches the real key to the prefix followed by
+//! the dotted name with periods replaced by underscores (so `"DATABASE.HOST"` under
+//! `prefix = "MYAPP_"` reads `MYAPP_DATABASE_HOST` instead of the literal `"DATABASE.HOST"`), and
+//! `root = "..."` replaces the default `Vars` root struct name (and the prefix on the generated
+//! error types) so multiple invocations can coexist in one crate, even in the same module.
 //! ```
 //! use env_vars_struct::env_vars_struct;
 //!
@@ -15,8 +41,8 @@
 //!
 //! // safety: no \0, =, or NUL here and nobody should do this in practice
 //! unsafe {
-//!     std::env::set_var("DATABASE_HOST", "host");
-//!     std::env::set_var("DATABASE_PORT", "5432");
+//!     std::env::set_var("DATABASE.HOST", "host");
+//!     std::env::set_var("DATABASE.PORT", "5432");
 //!     std::env::set_var("API.KEY", "magic key");
 //!     std::env::set_var("API.SECRET", "magic secret");
 //!     std::env::set_var("CACHE.REDIS.URL", "redis://someplace");
@@ -34,16 +60,197 @@
 #![deny(unsafe_op_in_unsafe_fn)]
 
 use proc_macro::TokenStream;
-use quote::quote;
+use quote::{format_ident, quote};
 use std::collections::HashMap;
 use syn::{LitStr, parse_macro_input};
 
 struct EnvVarsInput {
+    prefix: String,
+    root: String,
     vars: Vec<String>,
 }
 
 const ROOT_STRUCT_NAME: &str = "Vars";
 
+/// The type a leaf variable's value is converted into.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+enum Conversion {
+    /// Left as-is, i.e. a plain `String`. The default when no `:TYPE` suffix is given.
+    #[default]
+    Bytes,
+    /// Parsed with `str::parse::<i64>`.
+    Integer,
+    /// Parsed with `str::parse::<f64>`.
+    Float,
+    /// Parsed from the literal strings `"true"`/`"false"`.
+    Boolean,
+    /// Parsed as an RFC 3339 timestamp into `chrono::DateTime<chrono::Utc>`.
+    Timestamp,
+    /// Parsed as a naive/local timestamp with an explicit `chrono` format string, e.g.
+    /// `"ts=%Y-%m-%dT%H:%M:%S"`, into `chrono::DateTime<chrono::Utc>`.
+    TimestampFmt(String),
+    /// Parsed as a timezone-aware timestamp with an explicit `chrono` format string that
+    /// includes an offset token, e.g. `"tstz=%Y-%m-%d %H:%M:%S%z"`, into
+    /// `chrono::DateTime<chrono::FixedOffset>`.
+    TimestampTZFmt(String),
+}
+
+impl Conversion {
+    /// Parses the portion of a variable name following a `:`, e.g. `"int"` in `"PORT:int"`.
+    fn from_suffix(suffix: &str) -> Conversion {
+        if let Some(fmt) = suffix.strip_prefix("ts=") {
+            return Conversion::TimestampFmt(fmt.to_string());
+        }
+        if let Some(fmt) = suffix.strip_prefix("tstz=") {
+            return Conversion::TimestampTZFmt(fmt.to_string());
+        }
+
+        match suffix {
+            "int" | "integer" => Conversion::Integer,
+            "float" => Conversion::Float,
+            "bool" | "boolean" => Conversion::Boolean,
+            "string" | "bytes" | "asis" => Conversion::Bytes,
+            "ts" | "timestamp" => Conversion::Timestamp,
+            other => panic!("unknown type annotation `{other}`"),
+        }
+    }
+
+    /// The Rust type a field of this conversion is generated with.
+    fn rust_type(&self) -> proc_macro2::TokenStream {
+        match self {
+            Conversion::Bytes => quote! { String },
+            Conversion::Integer => quote! { i64 },
+            Conversion::Float => quote! { f64 },
+            Conversion::Boolean => quote! { bool },
+            Conversion::Timestamp | Conversion::TimestampFmt(_) => {
+                quote! { chrono::DateTime<chrono::Utc> }
+            }
+            Conversion::TimestampTZFmt(_) => quote! { chrono::DateTime<chrono::FixedOffset> },
+        }
+    }
+}
+
+/// Splits a raw variable name into its env var path and its requested [`Conversion`]. The name
+/// must already have any `?`/`=` modifier stripped off.
+fn parse_type(raw: &str) -> (&str, Conversion) {
+    match raw.split_once(':') {
+        Some((path, suffix)) => (path, Conversion::from_suffix(suffix)),
+        None => (raw, Conversion::Bytes),
+    }
+}
+
+/// The annotation parsed off a single variable name, beyond its dotted path: the requested
+/// [`Conversion`], whether it is optional (`?`), and whether it carries a default value (`=`).
+struct LeafSpec {
+    conversion: Conversion,
+    optional: bool,
+    default: Option<String>,
+}
+
+/// Splits a raw variable name like `"API.TIMEOUT:int=30"` or `"API.TIMEOUT?"` into its env var
+/// path and [`LeafSpec`].
+fn parse_leaf_name(raw: &str) -> (&str, LeafSpec) {
+    // The `?` optional marker only applies before any `=`-introduced default value - a `?`
+    // appearing inside the default itself (e.g. a URL's query string) isn't the marker.
+    let eq_idx = raw.find('=');
+    let pre_default = match eq_idx {
+        Some(idx) => &raw[..idx],
+        None => raw,
+    };
+
+    if let Some(question_idx) = pre_default.find('?') {
+        if question_idx != pre_default.len() - 1 {
+            panic!("the `?` optional marker must be the last character before any default value: `{raw}`");
+        }
+        if eq_idx.is_some() {
+            panic!("optional variables (`?`) cannot also carry a default value: `{raw}`");
+        }
+
+        let (path, conversion) = parse_type(&raw[..question_idx]);
+        return (
+            path,
+            LeafSpec {
+                conversion,
+                optional: true,
+                default: None,
+            },
+        );
+    }
+
+    // A `:` only introduces a type suffix when it appears before any `=` - otherwise the `=`
+    // starts a default value, which may itself contain colons (e.g. a URL).
+    let type_colon = match (raw.find(':'), raw.find('=')) {
+        (Some(colon_idx), Some(eq_idx)) if colon_idx < eq_idx => Some(colon_idx),
+        (Some(colon_idx), None) => Some(colon_idx),
+        _ => None,
+    };
+
+    if let Some(colon_idx) = type_colon {
+        let path = &raw[..colon_idx];
+        let rest = &raw[colon_idx + 1..];
+
+        if let Some(fmt) = rest.strip_prefix("ts=") {
+            return (
+                path,
+                LeafSpec {
+                    conversion: Conversion::TimestampFmt(fmt.to_string()),
+                    optional: false,
+                    default: None,
+                },
+            );
+        }
+        if let Some(fmt) = rest.strip_prefix("tstz=") {
+            return (
+                path,
+                LeafSpec {
+                    conversion: Conversion::TimestampTZFmt(fmt.to_string()),
+                    optional: false,
+                    default: None,
+                },
+            );
+        }
+        if let Some((type_name, default)) = rest.split_once('=') {
+            return (
+                path,
+                LeafSpec {
+                    conversion: Conversion::from_suffix(type_name),
+                    optional: false,
+                    default: Some(default.to_string()),
+                },
+            );
+        }
+
+        return (
+            path,
+            LeafSpec {
+                conversion: Conversion::from_suffix(rest),
+                optional: false,
+                default: None,
+            },
+        );
+    }
+
+    if let Some((path, default)) = raw.split_once('=') {
+        return (
+            path,
+            LeafSpec {
+                conversion: Conversion::Bytes,
+                optional: false,
+                default: Some(default.to_string()),
+            },
+        );
+    }
+
+    (
+        raw,
+        LeafSpec {
+            conversion: Conversion::Bytes,
+            optional: false,
+            default: None,
+        },
+    )
+}
+
 #[proc_macro]
 pub fn env_vars_struct(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as EnvVarsInput);
@@ -51,14 +258,33 @@ pub fn env_vars_struct(input: TokenStream) -> TokenStream {
     let mut root = Node::default();
 
     for var_name in &input.vars {
-        let parts = var_name.split('.').collect::<Vec<_>>();
-        insert_path(&mut root, &parts, var_name);
+        let (path, spec) = parse_leaf_name(var_name);
+        let parts = path.split('.').collect::<Vec<_>>();
+        // Only apply the dot-to-underscore rewrite when a prefix is actually configured, so
+        // invocations without `prefix = "..."` keep reading the literal dotted variable name.
+        let env_key = if input.prefix.is_empty() {
+            path.to_string()
+        } else {
+            format!("{}{}", input.prefix, path.replace('.', "_"))
+        };
+        insert_path(&mut root, &parts, &env_key, spec);
     }
 
-    let structs = generate_structs(&root, ROOT_STRUCT_NAME);
-    let root_struct = generate_root_struct(&root);
+    let var_error_ident = format_ident!("{}VarError", input.root);
+    let var_error_kind_ident = format_ident!("{}VarErrorKind", input.root);
+    let vars_error_ident = format_ident!("{}VarsError", input.root);
+    let err = ErrorIdents {
+        var_error: &var_error_ident,
+        var_error_kind: &var_error_kind_ident,
+        vars_error: &vars_error_ident,
+    };
+
+    let error_types = generate_error_types(&err);
+    let structs = generate_structs(&root, &input.root, &err);
+    let root_struct = generate_root_struct(&root, &input.root, &err);
 
     let expanded = quote! {
+        #error_types
         #structs
         #root_struct
     };
@@ -70,25 +296,42 @@ pub fn env_vars_struct(input: TokenStream) -> TokenStream {
 struct Node {
     children: HashMap<String, Node>,
     leaf_var: Option<String>,
+    conversion: Conversion,
+    optional: bool,
+    default: Option<String>,
 }
 
-fn insert_path(node: &mut Node, parts: &[&str], full_var: &str) {
+/// The names of the `VarError`/`VarErrorKind`/`VarsError` types generated for one invocation,
+/// namespaced by the invocation's `root` struct name so that two invocations in the same scope
+/// (each with a distinct `root = "..."`) don't collide.
+struct ErrorIdents<'a> {
+    var_error: &'a syn::Ident,
+    var_error_kind: &'a syn::Ident,
+    vars_error: &'a syn::Ident,
+}
+
+fn insert_path(node: &mut Node, parts: &[&str], full_var: &str, spec: LeafSpec) {
     if parts.is_empty() {
         return;
     }
 
     if parts.len() == 1 {
-        node.children
-            .entry(parts[0].to_string())
-            .or_default()
-            .leaf_var = Some(full_var.to_string());
+        let leaf = node.children.entry(parts[0].to_string()).or_default();
+        leaf.leaf_var = Some(full_var.to_string());
+        leaf.conversion = spec.conversion;
+        leaf.optional = spec.optional;
+        leaf.default = spec.default;
     } else {
         let child = node.children.entry(parts[0].to_string()).or_default();
-        insert_path(child, &parts[1..], full_var);
+        insert_path(child, &parts[1..], full_var, spec);
     }
 }
 
-fn generate_structs(node: &Node, struct_name: &str) -> proc_macro2::TokenStream {
+fn generate_structs(
+    node: &Node,
+    struct_name: &str,
+    err: &ErrorIdents,
+) -> proc_macro2::TokenStream {
     let mut output = proc_macro2::TokenStream::new();
 
     for (field_name, child_node) in &node.children {
@@ -98,11 +341,12 @@ fn generate_structs(node: &Node, struct_name: &str) -> proc_macro2::TokenStream
             let child_struct_ident =
                 syn::Ident::new(&child_struct_name, proc_macro2::Span::call_site());
 
-            let child_structs = generate_structs(child_node, &child_struct_name);
+            let child_structs = generate_structs(child_node, &child_struct_name, err);
             output.extend(child_structs);
 
             let fields = generate_struct_fields(child_node, &child_struct_name);
-            let field_inits = generate_field_inits(child_node, &child_struct_name);
+            let try_new_fn =
+                generate_try_new_fn(child_node, &child_struct_name, quote! {}, err);
 
             output.extend(quote! {
                 #[derive(Debug, Clone)]
@@ -112,10 +356,10 @@ fn generate_structs(node: &Node, struct_name: &str) -> proc_macro2::TokenStream
 
                 impl #child_struct_ident {
                     fn new() -> Self {
-                        Self {
-                            #field_inits
-                        }
+                        Self::try_new().unwrap_or_else(|e| panic!("{e}"))
                     }
+
+                    #try_new_fn
                 }
             });
         }
@@ -124,26 +368,30 @@ fn generate_structs(node: &Node, struct_name: &str) -> proc_macro2::TokenStream
     output
 }
 
-fn generate_root_struct(node: &Node) -> proc_macro2::TokenStream {
-    let struct_name = syn::Ident::new(ROOT_STRUCT_NAME, proc_macro2::Span::call_site());
-    let fields = generate_struct_fields(node, ROOT_STRUCT_NAME);
-    let field_inits = generate_field_inits(node, ROOT_STRUCT_NAME);
+fn generate_root_struct(
+    node: &Node,
+    struct_name: &str,
+    err: &ErrorIdents,
+) -> proc_macro2::TokenStream {
+    let struct_ident = syn::Ident::new(struct_name, proc_macro2::Span::call_site());
+    let fields = generate_struct_fields(node, struct_name);
+    let try_new_fn = generate_try_new_fn(node, struct_name, quote! { pub }, err);
 
     quote! {
         #[derive(Debug, Clone)]
-        pub struct #struct_name {
+        pub struct #struct_ident {
             #fields
         }
 
-        impl #struct_name {
+        impl #struct_ident {
             pub fn new() -> Self {
-                Self {
-                    #field_inits
-                }
+                Self::try_new().unwrap_or_else(|e| panic!("{e}"))
             }
+
+            #try_new_fn
         }
 
-        impl Default for #struct_name {
+        impl Default for #struct_ident {
             fn default() -> Self {
                 Self::new()
             }
@@ -160,8 +408,14 @@ fn generate_struct_fields(node: &Node, parent_struct: &str) -> proc_macro2::Toke
 
         if child_node.leaf_var.is_some() && child_node.children.is_empty() {
             // leaf
+            let field_type = child_node.conversion.rust_type();
+            let field_type = if child_node.optional {
+                quote! { Option<#field_type> }
+            } else {
+                field_type
+            };
             fields.extend(quote! {
-                pub #field_ident: String,
+                pub #field_ident: #field_type,
             });
         } else {
             // not a leaf
@@ -178,8 +432,18 @@ fn generate_struct_fields(node: &Node, parent_struct: &str) -> proc_macro2::Toke
     fields
 }
 
-fn generate_field_inits(node: &Node, parent_struct: &str) -> proc_macro2::TokenStream {
-    let mut inits = proc_macro2::TokenStream::new();
+/// Builds a generated `try_new()` function, which reads every leaf in `node`, recurses into
+/// child structs, and collects every problem encountered instead of stopping at the first one.
+fn generate_try_new_fn(
+    node: &Node,
+    parent_struct: &str,
+    visibility: proc_macro2::TokenStream,
+    err: &ErrorIdents,
+) -> proc_macro2::TokenStream {
+    let mut locals = proc_macro2::TokenStream::new();
+    let mut finals = proc_macro2::TokenStream::new();
+    let var_error_ident = err.var_error;
+    let vars_error_ident = err.vars_error;
 
     for (field_name, child_node) in &node.children {
         let field_ident =
@@ -189,9 +453,15 @@ fn generate_field_inits(node: &Node, parent_struct: &str) -> proc_macro2::TokenS
             && child_node.children.is_empty()
         {
             // leaf
-            inits.extend(quote! {
-                #field_ident: std::env::var(#var_name)
-                    .unwrap_or_else(|_| panic!("Environment variable {} not found", #var_name)),
+            let value = generate_leaf_try_value(var_name, child_node, err);
+            locals.extend(quote! {
+                let #field_ident = match #value {
+                    Ok(v) => Some(v),
+                    Err(kind) => {
+                        __errors.push(#var_error_ident { var: #var_name.to_string(), kind });
+                        None
+                    }
+                };
             });
         } else {
             // has children
@@ -199,13 +469,178 @@ fn generate_field_inits(node: &Node, parent_struct: &str) -> proc_macro2::TokenS
             let child_struct_ident =
                 syn::Ident::new(&child_struct_name, proc_macro2::Span::call_site());
 
-            inits.extend(quote! {
-                #field_ident: #child_struct_ident::new(),
+            locals.extend(quote! {
+                let #field_ident = match #child_struct_ident::try_new() {
+                    Ok(v) => Some(v),
+                    Err(e) => {
+                        __errors.extend(e.problems);
+                        None
+                    }
+                };
             });
         }
+
+        finals.extend(quote! {
+            #field_ident: #field_ident.unwrap(),
+        });
+    }
+
+    quote! {
+        #visibility fn try_new() -> Result<Self, #vars_error_ident> {
+            let mut __errors: Vec<#var_error_ident> = Vec::new();
+
+            #locals
+
+            if !__errors.is_empty() {
+                return Err(#vars_error_ident { problems: __errors });
+            }
+
+            Ok(Self {
+                #finals
+            })
+        }
+    }
+}
+
+/// Builds the expression that converts an in-scope `raw: String` into the field's type, yielding
+/// `Ok(value)` or `Err(kind)` describing why the conversion failed.
+fn generate_convert_expr(conversion: &Conversion, err: &ErrorIdents) -> proc_macro2::TokenStream {
+    let var_error_kind_ident = err.var_error_kind;
+    match conversion {
+        Conversion::Bytes => quote! { Ok(raw) },
+        Conversion::Integer => quote! {
+            raw.parse::<i64>()
+                .map_err(|_| #var_error_kind_ident::InvalidValue { reason: "not a valid integer".to_string() })
+        },
+        Conversion::Float => quote! {
+            raw.parse::<f64>()
+                .map_err(|_| #var_error_kind_ident::InvalidValue { reason: "not a valid float".to_string() })
+        },
+        Conversion::Boolean => quote! {
+            match raw.as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(#var_error_kind_ident::InvalidValue { reason: "not a valid boolean".to_string() }),
+            }
+        },
+        Conversion::Timestamp => quote! {
+            chrono::DateTime::parse_from_rfc3339(&raw)
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .map_err(|_| #var_error_kind_ident::InvalidValue { reason: "not a valid RFC 3339 timestamp".to_string() })
+        },
+        Conversion::TimestampFmt(fmt) => quote! {
+            chrono::NaiveDateTime::parse_from_str(&raw, #fmt)
+                .ok()
+                .and_then(|dt| dt.and_local_timezone(chrono::Local).single())
+                .map(|dt| dt.with_timezone(&chrono::Utc))
+                .ok_or_else(|| #var_error_kind_ident::InvalidValue { reason: format!("does not match format {}", #fmt) })
+        },
+        Conversion::TimestampTZFmt(fmt) => quote! {
+            chrono::DateTime::parse_from_str(&raw, #fmt)
+                .map_err(|_| #var_error_kind_ident::InvalidValue { reason: format!("does not match format {}", #fmt) })
+        },
     }
+}
+
+/// Builds the expression that reads and converts a single leaf's value at runtime, yielding
+/// `Ok(value)` or `Err(kind)` describing why the variable could not be loaded. Honors the leaf's
+/// `optional` and `default` annotations: an optional leaf yields `Ok(None)` when unset instead of
+/// an error, and a defaulted leaf falls back to converting its default literal instead of erroring.
+fn generate_leaf_try_value(
+    var_name: &str,
+    node: &Node,
+    err: &ErrorIdents,
+) -> proc_macro2::TokenStream {
+    let convert = generate_convert_expr(&node.conversion, err);
+    let var_error_kind_ident = err.var_error_kind;
+
+    if node.optional {
+        quote! {
+            match std::env::var(#var_name) {
+                Ok(raw) => (#convert).map(Some),
+                Err(_) => Ok(None),
+            }
+        }
+    } else if let Some(default) = &node.default {
+        quote! {
+            match std::env::var(#var_name) {
+                Ok(raw) => (#convert),
+                Err(_) => {
+                    let raw = #default.to_string();
+                    (#convert)
+                }
+            }
+        }
+    } else {
+        quote! {
+            match std::env::var(#var_name) {
+                Ok(raw) => (#convert),
+                Err(_) => Err(#var_error_kind_ident::Missing),
+            }
+        }
+    }
+}
+
+/// Generates the `VarError`/`VarErrorKind`/`VarsError` types emitted once per macro invocation,
+/// named per `ErrorIdents` (prefixed with the invocation's `root`) so that two invocations in the
+/// same scope with distinct `root = "..."` values don't collide on these type names.
+fn generate_error_types(err: &ErrorIdents) -> proc_macro2::TokenStream {
+    let var_error_ident = err.var_error;
+    let var_error_kind_ident = err.var_error_kind;
+    let vars_error_ident = err.vars_error;
+
+    quote! {
+        /// Why a single environment variable could not be loaded.
+        #[derive(Debug, Clone)]
+        pub enum #var_error_kind_ident {
+            /// The variable was not set.
+            Missing,
+            /// The variable was set, but its value could not be converted to the expected type.
+            InvalidValue {
+                /// A human-readable description of why the conversion failed.
+                reason: String,
+            },
+        }
 
-    inits
+        /// A single environment variable that could not be loaded, and why.
+        #[derive(Debug, Clone)]
+        pub struct #var_error_ident {
+            /// The name of the environment variable that caused the problem.
+            pub var: String,
+            /// What went wrong.
+            pub kind: #var_error_kind_ident,
+        }
+
+        impl std::fmt::Display for #var_error_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match &self.kind {
+                    #var_error_kind_ident::Missing => write!(f, "{} is not set", self.var),
+                    #var_error_kind_ident::InvalidValue { reason } => write!(f, "{}: {}", self.var, reason),
+                }
+            }
+        }
+
+        impl std::error::Error for #var_error_ident {}
+
+        /// Every problem encountered while loading a generated struct via `try_new`.
+        #[derive(Debug, Clone, Default)]
+        pub struct #vars_error_ident {
+            /// One entry per environment variable that was missing or failed to convert.
+            pub problems: Vec<#var_error_ident>,
+        }
+
+        impl std::fmt::Display for #vars_error_ident {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                writeln!(f, "{} environment variable(s) could not be loaded:", self.problems.len())?;
+                for problem in &self.problems {
+                    writeln!(f, "  - {problem}")?;
+                }
+                Ok(())
+            }
+        }
+
+        impl std::error::Error for #vars_error_ident {}
+    }
 }
 
 fn to_pascal_case(s: &str) -> String {
@@ -229,6 +664,43 @@ fn to_snake_case(s: &str) -> String {
 
 impl syn::parse::Parse for EnvVarsInput {
     fn parse(input: syn::parse::ParseStream) -> syn::Result<Self> {
+        let mut prefix = String::new();
+        let mut root = ROOT_STRUCT_NAME.to_string();
+
+        while input.peek(syn::Ident) && input.peek2(syn::Token![=]) {
+            let key: syn::Ident = input.parse()?;
+            let _: syn::Token![=] = input.parse()?;
+            let value: LitStr = input.parse()?;
+
+            match key.to_string().as_str() {
+                "prefix" => prefix = value.value(),
+                "root" => {
+                    if syn::parse_str::<syn::Ident>(&value.value()).is_err() {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            format!(
+                                "`root` must be a valid Rust identifier, got `{}`",
+                                value.value()
+                            ),
+                        ));
+                    }
+                    root = value.value();
+                }
+                other => {
+                    return Err(syn::Error::new(
+                        key.span(),
+                        format!("unknown env_vars_struct config key `{other}`"),
+                    ));
+                }
+            }
+
+            if input.is_empty() {
+                break;
+            }
+
+            let _: syn::Token![,] = input.parse()?;
+        }
+
         let mut vars = Vec::new();
 
         while !input.is_empty() {
@@ -242,6 +714,6 @@ impl syn::parse::Parse for EnvVarsInput {
             let _: syn::Token![,] = input.parse()?;
         }
 
-        Ok(EnvVarsInput { vars })
+        Ok(EnvVarsInput { prefix, root, vars })
     }
 }
@@ -0,0 +1,225 @@
+//! Behavior tests for the code `env_vars_struct!` generates. Each case lives in its own module so
+//! its macro invocation gets its own scope - otherwise two invocations in the same module would
+//! collide on the generated `Vars`/`VarsVarError`/`VarsVarsError` names. Variable names are also unique
+//! per test function, since `cargo test` runs tests in the same binary concurrently and they'd
+//! otherwise race over the same process-wide environment.
+
+mod typed_conversions {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "TYPED_CONVERSIONS.PORT:int",
+        "TYPED_CONVERSIONS.RATIO:float",
+        "TYPED_CONVERSIONS.ENABLED:bool",
+    );
+
+    #[test]
+    fn parses_into_typed_fields() {
+        unsafe {
+            std::env::set_var("TYPED_CONVERSIONS.PORT", "5432");
+            std::env::set_var("TYPED_CONVERSIONS.RATIO", "0.5");
+            std::env::set_var("TYPED_CONVERSIONS.ENABLED", "true");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(vars.typed_conversions.port, 5432);
+        assert_eq!(vars.typed_conversions.ratio, 0.5);
+        assert!(vars.typed_conversions.enabled);
+    }
+}
+
+mod timestamps {
+    use chrono::{Datelike, Timelike};
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "TIMESTAMPS.RFC.TIME:ts",
+        "TIMESTAMPS.LOCAL.TIME:ts=%Y-%m-%dT%H:%M:%S",
+        "TIMESTAMPS.TZ.TIME:tstz=%Y-%m-%d %H:%M:%S%z",
+    );
+
+    #[test]
+    fn parses_timestamp_formats() {
+        unsafe {
+            std::env::set_var("TIMESTAMPS.RFC.TIME", "2024-01-01T00:00:00Z");
+            std::env::set_var("TIMESTAMPS.LOCAL.TIME", "2024-06-15T12:30:00");
+            std::env::set_var("TIMESTAMPS.TZ.TIME", "2024-01-01 00:00:00+0000");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(vars.timestamps.rfc.time.year(), 2024);
+        assert_eq!(vars.timestamps.local.time.with_timezone(&chrono::Local).hour(), 12);
+        assert_eq!(vars.timestamps.tz.time.year(), 2024);
+    }
+}
+
+mod try_new_aggregates_failures {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "TRY_NEW_AGGREGATES_FAILURES.DATABASE.HOST",
+        "TRY_NEW_AGGREGATES_FAILURES.DATABASE.PORT:int",
+        "TRY_NEW_AGGREGATES_FAILURES.CACHE.TTL:int",
+    );
+
+    #[test]
+    fn reports_every_missing_or_invalid_variable_at_once() {
+        unsafe {
+            std::env::remove_var("TRY_NEW_AGGREGATES_FAILURES.DATABASE.HOST");
+            std::env::set_var("TRY_NEW_AGGREGATES_FAILURES.DATABASE.PORT", "not-a-number");
+            std::env::remove_var("TRY_NEW_AGGREGATES_FAILURES.CACHE.TTL");
+        }
+
+        let err = Vars::try_new().expect_err("all three variables are missing/invalid");
+        assert_eq!(err.problems.len(), 3);
+
+        let named: Vec<_> = err.problems.iter().map(|p| p.var.as_str()).collect();
+        assert!(named.contains(&"TRY_NEW_AGGREGATES_FAILURES.DATABASE.HOST"));
+        assert!(named.contains(&"TRY_NEW_AGGREGATES_FAILURES.DATABASE.PORT"));
+        assert!(named.contains(&"TRY_NEW_AGGREGATES_FAILURES.CACHE.TTL"));
+    }
+}
+
+mod try_new_succeeds {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "TRY_NEW_SUCCEEDS.DATABASE.HOST",
+        "TRY_NEW_SUCCEEDS.DATABASE.PORT:int",
+        "TRY_NEW_SUCCEEDS.CACHE.TTL:int",
+    );
+
+    #[test]
+    fn succeeds_when_everything_is_present_and_valid() {
+        unsafe {
+            std::env::set_var("TRY_NEW_SUCCEEDS.DATABASE.HOST", "host");
+            std::env::set_var("TRY_NEW_SUCCEEDS.DATABASE.PORT", "5432");
+            std::env::set_var("TRY_NEW_SUCCEEDS.CACHE.TTL", "30");
+        }
+
+        let vars = Vars::try_new().expect("everything is set and valid");
+        assert_eq!(vars.try_new_succeeds.database.host, "host");
+        assert_eq!(vars.try_new_succeeds.database.port, 5432);
+        assert_eq!(vars.try_new_succeeds.cache.ttl, 30);
+    }
+}
+
+mod optional_unset {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "OPTIONAL_UNSET.TIMEOUT?",
+        "OPTIONAL_UNSET.RETRIES:int=3",
+    );
+
+    #[test]
+    fn falls_back_when_unset() {
+        unsafe {
+            std::env::remove_var("OPTIONAL_UNSET.TIMEOUT");
+            std::env::remove_var("OPTIONAL_UNSET.RETRIES");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(vars.optional_unset.timeout, None);
+        assert_eq!(vars.optional_unset.retries, 3);
+    }
+}
+
+mod optional_set {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        "OPTIONAL_SET.TIMEOUT?",
+        "OPTIONAL_SET.RETRIES:int=3",
+    );
+
+    #[test]
+    fn uses_the_value_when_set() {
+        unsafe {
+            std::env::set_var("OPTIONAL_SET.TIMEOUT", "42");
+            std::env::set_var("OPTIONAL_SET.RETRIES", "7");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(vars.optional_set.timeout, Some("42".to_string()));
+        assert_eq!(vars.optional_set.retries, 7);
+    }
+}
+
+mod prefix_and_root {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(
+        prefix = "PREFIX_AND_ROOT_",
+        root = "PrefixedConfig",
+        "DATABASE.HOST",
+    );
+
+    #[test]
+    fn reads_the_prefixed_underscored_key() {
+        unsafe {
+            std::env::set_var("PREFIX_AND_ROOT_DATABASE_HOST", "host");
+        }
+
+        let cfg = PrefixedConfig::new();
+        assert_eq!(cfg.database.host, "host");
+    }
+}
+
+mod without_prefix {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!("WITHOUT_PREFIX.HOST",);
+
+    #[test]
+    fn reads_the_literal_dotted_key_unchanged() {
+        unsafe {
+            std::env::set_var("WITHOUT_PREFIX.HOST", "host");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(vars.without_prefix.host, "host");
+    }
+}
+
+mod default_value_containing_a_question_mark {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!("DEFAULT_VALUE_CONTAINING_A_QUESTION_MARK.URL=redis://localhost:6379/0?pool_size=5");
+
+    #[test]
+    fn question_mark_inside_a_default_is_not_mistaken_for_the_optional_marker() {
+        unsafe {
+            std::env::remove_var("DEFAULT_VALUE_CONTAINING_A_QUESTION_MARK.URL");
+        }
+
+        let vars = Vars::new();
+        assert_eq!(
+            vars.default_value_containing_a_question_mark.url,
+            "redis://localhost:6379/0?pool_size=5"
+        );
+    }
+}
+
+mod multiple_roots_in_one_scope {
+    use env_vars_struct::env_vars_struct;
+
+    env_vars_struct!(root = "MultiRootsFirst", "MULTIPLE_ROOTS.FIRST.HOST",);
+    env_vars_struct!(root = "MultiRootsSecond", "MULTIPLE_ROOTS.SECOND.HOST",);
+
+    #[test]
+    fn distinct_roots_dont_collide_on_error_types_or_struct_names() {
+        unsafe {
+            std::env::set_var("MULTIPLE_ROOTS.FIRST.HOST", "first-host");
+            std::env::remove_var("MULTIPLE_ROOTS.SECOND.HOST");
+        }
+
+        let first = MultiRootsFirst::new();
+        assert_eq!(first.multiple_roots.first.host, "first-host");
+
+        let err = MultiRootsSecond::try_new()
+            .expect_err("MULTIPLE_ROOTS.SECOND.HOST is unset");
+        assert_eq!(err.problems.len(), 1);
+        assert_eq!(err.problems[0].var, "MULTIPLE_ROOTS.SECOND.HOST");
+    }
+}